@@ -0,0 +1,32 @@
+//! Builds the `.debug_gdb_scripts` section body that gets embedded into the crate's object file
+//! for every `#[debugger_visualizer(gdb_script_file = "...")]`/`gdb_script_file`-style visualizer
+//! collected from the crate.
+
+use rustc_middle::ty::TyCtxt;
+use rustc_span::def_id::LOCAL_CRATE;
+use rustc_span::DebuggerVisualizerType;
+
+/// The name of the ELF/Mach-O section that GDB and LLDB scan for embedded pretty-printer
+/// scripts. A debugger that supports `.debug_gdb_scripts` auto-loads every script it finds
+/// there, the same way `windbg`/Visual Studio auto-load `.natvis` files listed via `/NATVIS:`
+/// on the MSVC linker command line.
+pub const GDB_SCRIPT_SECTION: &str = ".debug_gdb_scripts";
+
+/// Byte that precedes an inline Python pretty-printer script inside `.debug_gdb_scripts`, per
+/// the format GDB documents for `PYTHON_SCRIPT` auto-load entries.
+const GDB_PYTHON_SCRIPT_MARKER: u8 = 4;
+
+/// Builds the `.debug_gdb_scripts` section contents for this crate, by concatenating every
+/// collected GDB/LLDB pretty-printer script with its auto-load marker. Returns `None` if the
+/// crate has no such visualizers, so callers can skip adding an empty section.
+pub fn gdb_script_section_contents(tcx: TyCtxt<'_>) -> Option<Vec<u8>> {
+    let mut section = Vec::new();
+    for visualizer in tcx.debugger_visualizers(LOCAL_CRATE) {
+        if visualizer.visualizer_type == DebuggerVisualizerType::GdbPrettyPrinter {
+            section.push(GDB_PYTHON_SCRIPT_MARKER);
+            section.extend_from_slice(&visualizer.contents);
+            section.push(0);
+        }
+    }
+    (!section.is_empty()).then_some(section)
+}
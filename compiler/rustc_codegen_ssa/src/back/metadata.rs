@@ -0,0 +1,21 @@
+//! Helpers for embedding backend-agnostic debug sections into the object file a backend builds.
+
+use object::write::Object;
+use object::{SectionKind, StandardSegment};
+
+use rustc_middle::ty::TyCtxt;
+
+use super::debugger_visualizer::{gdb_script_section_contents, GDB_SCRIPT_SECTION};
+
+/// Adds this crate's `.debug_gdb_scripts` section, if it has any GDB/LLDB pretty-printer
+/// visualizers, to `object` - an object file a backend is already building from the crate's
+/// compiled code - so GDB/LLDB auto-load the scripts when the binary is debugged. Does nothing
+/// if the crate has none, so a backend can call this unconditionally alongside whatever other
+/// debug sections it embeds, without first serializing a separate, empty object file.
+pub fn embed_gdb_scripts(tcx: TyCtxt<'_>, object: &mut Object<'_>) {
+    let Some(contents) = gdb_script_section_contents(tcx) else { return };
+    let segment = object.segment_name(StandardSegment::Debug).to_vec();
+    let section =
+        object.add_section(segment, GDB_SCRIPT_SECTION.as_bytes().to_vec(), SectionKind::Debug);
+    object.append_section_data(section, &contents, 1);
+}
@@ -0,0 +1,4 @@
+//! Backend-agnostic linking and output helpers.
+
+pub mod debugger_visualizer;
+pub mod metadata;
@@ -0,0 +1,4 @@
+//! Code that is used by all compiler backends that choose to implement themselves against this
+//! crate's backend-agnostic abstractions.
+
+pub mod back;
@@ -9,7 +9,7 @@ use rustc_hir::HirId;
 use rustc_middle::ty::query::Providers;
 use rustc_middle::ty::TyCtxt;
 use rustc_span::def_id::LOCAL_CRATE;
-use rustc_span::{sym, DebuggerVisualizerFile, DebuggerVisualizerType};
+use rustc_span::{sym, DebuggerVisualizerFile, DebuggerVisualizerType, Symbol};
 
 use std::sync::Arc;
 
@@ -34,10 +34,29 @@ fn check_for_debugger_visualizer<'tcx>(
                 _ => continue,
             };
 
-            let file = match (meta_item.name_or_empty(), meta_item.value_str()) {
+            // `natvis` takes its visualizer contents directly from the attribute, while
+            // `natvis_file` and `gdb_script_file` name a file to resolve and read.
+            let (file, visualizer_type) = match (meta_item.name_or_empty(), meta_item.value_str())
+            {
+                (sym::natvis, Some(value)) => {
+                    debugger_visualizers.insert(DebuggerVisualizerFile::new(
+                        Arc::from(value.as_str().as_bytes()),
+                        DebuggerVisualizerType::Natvis,
+                    ));
+                    continue;
+                }
                 (sym::natvis_file, Some(value)) => {
                     match resolve_path(&tcx.sess.parse_sess, value.as_str(), attr.span) {
-                        Ok(file) => file,
+                        Ok(file) => (file, DebuggerVisualizerType::Natvis),
+                        Err(mut err) => {
+                            err.emit();
+                            continue;
+                        }
+                    }
+                }
+                (sym::gdb_script_file, Some(value)) => {
+                    match resolve_path(&tcx.sess.parse_sess, value.as_str(), attr.span) {
+                        Ok(file) => (file, DebuggerVisualizerType::GdbPrettyPrinter),
                         Err(mut err) => {
                             err.emit();
                             continue;
@@ -48,7 +67,16 @@ fn check_for_debugger_visualizer<'tcx>(
             };
 
             if file.is_file() {
-                let contents = match std::fs::read(&file) {
+                // Load through the source map, the same as `include_bytes!` does, rather than
+                // `std::fs::read` directly. `load_binary_file` reads the raw bytes (a visualizer
+                // file, unlike Rust source, has no reason to be valid UTF-8) and registers the
+                // file with the source map's own tracked-source-file set, so the query result's
+                // fingerprint (it carries these bytes) and the incremental session's checksum
+                // check both reflect the file's contents. That tracking is separate from
+                // `file_depinfo`, which feeds `--emit dep-info` for external build tools and, like
+                // `include_bytes!`'s own expansion, has to be populated explicitly alongside the
+                // `load_binary_file` call - `load_binary_file` does not do it itself.
+                let contents = match tcx.sess.source_map().load_binary_file(&file) {
                     Ok(contents) => contents,
                     Err(err) => {
                         tcx.sess
@@ -65,10 +93,14 @@ fn check_for_debugger_visualizer<'tcx>(
                     }
                 };
 
-                debugger_visualizers.insert(DebuggerVisualizerFile::new(
-                    Arc::from(contents),
-                    DebuggerVisualizerType::Natvis,
-                ));
+                tcx.sess
+                    .parse_sess
+                    .file_depinfo
+                    .borrow_mut()
+                    .insert(Symbol::intern(&file.display().to_string()));
+
+                debugger_visualizers
+                    .insert(DebuggerVisualizerFile::new(Arc::from(contents), visualizer_type));
             } else {
                 tcx.sess
                     .struct_span_err(attr.span, &format!("{} is not a valid file", file.display()))
@@ -102,9 +134,38 @@ fn debugger_visualizers<'tcx>(tcx: TyCtxt<'tcx>, cnum: CrateNum) -> Vec<Debugger
 
     // Sort the visualizers so we always get a deterministic query result.
     visualizers.sort();
+
+    dump_debugger_visualizers_for_tests(tcx, &visualizers);
+
     visualizers
 }
 
+/// Test-only hook: with `#[rustc_dump_debugger_visualizers]` on the crate, emit the
+/// `debugger_visualizers` query result as a compile error, one per visualizer, so ui tests can
+/// assert on the type and contents the query actually produced with `//~ ERROR` patterns,
+/// instead of only asserting that the attribute parses.
+fn dump_debugger_visualizers_for_tests(tcx: TyCtxt<'_>, visualizers: &[DebuggerVisualizerFile]) {
+    let attrs = tcx.hir().attrs(CRATE_HIR_ID);
+    let Some(attr) = attrs.iter().find(|attr| attr.has_name(sym::rustc_dump_debugger_visualizers))
+    else {
+        return;
+    };
+
+    for visualizer in visualizers {
+        tcx.sess
+            .struct_span_err(
+                attr.span,
+                &format!(
+                    "debugger visualizer: {:?}, {} bytes: `{}`",
+                    visualizer.visualizer_type,
+                    visualizer.contents.len(),
+                    String::from_utf8_lossy(&visualizer.contents),
+                ),
+            )
+            .emit();
+    }
+}
+
 pub fn provide(providers: &mut Providers) {
     providers.debugger_visualizers = debugger_visualizers;
 }
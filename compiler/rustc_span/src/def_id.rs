@@ -0,0 +1,11 @@
+//! Crate- and definition-identifying types.
+
+rustc_index::newtype_index! {
+    pub struct CrateNum {
+        ENCODABLE = custom
+    }
+}
+
+/// Item definitions in the currently-compiled crate would have the `CrateNum`
+/// `LOCAL_CRATE` in their `DefId`.
+pub const LOCAL_CRATE: CrateNum = CrateNum::from_u32(0);
@@ -0,0 +1,40 @@
+//! Source positions and related helper functions.
+
+use rustc_macros::{Decodable, Encodable, HashStable_Generic};
+
+use std::sync::Arc;
+
+#[macro_use]
+mod symbol;
+
+pub mod def_id;
+
+pub use symbol::{sym, Symbol};
+
+/// Identifies the particular debugger that a visualizer targets.
+#[derive(HashStable_Generic, Eq, PartialEq, Ord, PartialOrd, Hash)]
+#[derive(Debug, Copy, Clone, Encodable, Decodable)]
+pub enum DebuggerVisualizerType {
+    /// A Natvis file, consumed by the MSVC debugger and debuggers that understand its format
+    /// (e.g. WinDbg).
+    Natvis,
+    /// A GDB/LLDB Python pretty-printer script, auto-loaded via a `.debug_gdb_scripts` section.
+    GdbPrettyPrinter,
+}
+
+/// A single debugger visualizer file, collected by the `debugger_visualizers` query and later
+/// embedded by codegen so that the relevant debugger can auto-load it.
+#[derive(HashStable_Generic, Eq, PartialEq, Ord, PartialOrd, Hash)]
+#[derive(Debug, Clone, Encodable, Decodable)]
+pub struct DebuggerVisualizerFile {
+    /// The complete debugger visualizer source.
+    pub contents: Arc<[u8]>,
+    /// Indicates which visualizer type this targets.
+    pub visualizer_type: DebuggerVisualizerType,
+}
+
+impl DebuggerVisualizerFile {
+    pub fn new(contents: Arc<[u8]>, visualizer_type: DebuggerVisualizerType) -> Self {
+        DebuggerVisualizerFile { contents, visualizer_type }
+    }
+}
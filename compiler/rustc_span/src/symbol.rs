@@ -0,0 +1,64 @@
+//! An "interner" is a data structure that associates values with usize tags and allows bidirectional
+//! lookup; i.e., given a value, one can easily find the type, and vice versa.
+//!
+//! This module contains the table of preinterned symbols used throughout the compiler; only the
+//! entries touched by the crates vendored into this tree are reproduced here.
+
+use crate::Symbol;
+
+symbols! {
+    // After modifying this list adjust `is_special`, `is_used_keyword`/`is_unused_keyword`
+    Keywords {
+        Underscore:         "_",
+        As:                 "as",
+        Break:              "break",
+        Const:              "const",
+        Continue:           "continue",
+        Crate:              "crate",
+        Else:               "else",
+        Fn:                 "fn",
+        For:                "for",
+        If:                 "if",
+        Impl:               "impl",
+        In:                 "in",
+        Let:                "let",
+        Loop:               "loop",
+        Match:              "match",
+        Mod:                "mod",
+        Move:               "move",
+        Mut:                "mut",
+        Pub:                "pub",
+        Ref:                "ref",
+        Return:             "return",
+        SelfLower:          "self",
+        SelfUpper:          "Self",
+        Static:             "static",
+        Struct:             "struct",
+        Super:              "super",
+        Trait:              "trait",
+        True:               "true",
+        Type:               "type",
+        Unsafe:             "unsafe",
+        Use:                "use",
+        Where:              "where",
+        While:              "while",
+    }
+
+    // Pre-interned symbols that can be referred to with `rustc_span::sym::*`.
+    //
+    // The symbol is the stringified identifier unless otherwise specified, in
+    // which case the name should be the stringified identifier used internally
+    // for back compat.
+    Symbols {
+        debug_assertions,
+        debugger_visualizer,
+        deny,
+        gdb_script_file,
+        natvis,
+        natvis_file,
+        rustc,
+        rustc_dump_debugger_visualizers,
+        test,
+        warn,
+    }
+}
@@ -0,0 +1,5 @@
+// check-pass
+
+#![debugger_visualizer(gdb_script_file = "gdb-script-file.py")]
+
+fn main() {}
@@ -0,0 +1,5 @@
+// check-pass
+
+#![debugger_visualizer(natvis_file = "natvis-file.natvis")]
+
+fn main() {}
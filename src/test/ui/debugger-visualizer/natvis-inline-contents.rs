@@ -0,0 +1,7 @@
+// Asserts that the inline form of `#[debugger_visualizer]` actually threads the literal's bytes
+// into the `debugger_visualizers` query result, not just that the attribute parses.
+#![debugger_visualizer(natvis = "<AutoVisualizer></AutoVisualizer>")]
+#![rustc_dump_debugger_visualizers]
+//~^ ERROR debugger visualizer: Natvis, 33 bytes: `<AutoVisualizer></AutoVisualizer>`
+
+fn main() {}
@@ -0,0 +1,11 @@
+// check-pass
+
+// Regression test for the inline form of `#[debugger_visualizer]`: the visualizer contents are
+// given directly in the attribute instead of naming a file to resolve and read.
+#![debugger_visualizer(natvis = "
+<?xml version=\"1.0\" encoding=\"utf-8\"?>
+<AutoVisualizer xmlns=\"http://schemas.microsoft.com/vstudio/debugger/natvis/2010\">
+</AutoVisualizer>
+")]
+
+fn main() {}